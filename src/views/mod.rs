@@ -0,0 +1,234 @@
+mod tab;
+
+pub use tab::*;
+
+use std::{
+    fmt,
+    hash::{BuildHasherDefault, Hash},
+};
+
+use indexmap::IndexSet;
+use leptos_reactive::ScopeDisposer;
+use rustc_hash::FxHasher;
+use smallvec::SmallVec;
+
+use crate::{app::AppContext, context::AppState, view::View};
+
+pub(crate) type FxIndexSet<T> = IndexSet<T, BuildHasherDefault<FxHasher>>;
+
+/// Wraps the previous run's hashed keys so `create_effect` can diff against
+/// them without forcing `Debug` on arbitrary key types.
+pub(crate) struct HashRun<T>(pub(crate) T);
+
+impl<T> fmt::Debug for HashRun<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("HashRun(..)")
+    }
+}
+
+pub(crate) struct DiffOpAdd<V> {
+    pub(crate) at: usize,
+    pub(crate) view: Option<V>,
+}
+
+pub(crate) struct DiffOpMove {
+    pub(crate) from: usize,
+    pub(crate) to: usize,
+    /// Whether this survivor lies on the longest increasing subsequence of
+    /// `new_index_to_old_index` — i.e. it's already in a position consistent
+    /// with the other in-place survivors, so a DOM/taffy reorder doesn't
+    /// need to touch it. An optimization hint only: the array relocation in
+    /// `apply_diff` happens for every `DiffOpMove` regardless of this flag.
+    pub(crate) in_place: bool,
+}
+
+pub(crate) struct DiffOpRemove {
+    pub(crate) at: usize,
+}
+
+pub(crate) struct Diff<V> {
+    pub(crate) removed: SmallVec<[DiffOpRemove; 8]>,
+    pub(crate) moved: SmallVec<[DiffOpMove; 8]>,
+    pub(crate) added: SmallVec<[DiffOpAdd<V>; 8]>,
+    pub(crate) clear: bool,
+}
+
+impl<V> Default for Diff<V> {
+    fn default() -> Self {
+        Self {
+            removed: Default::default(),
+            moved: Default::default(),
+            added: Default::default(),
+            clear: false,
+        }
+    }
+}
+
+/// Diffs the previous and current keyed item order.
+///
+/// Keys missing from `to` become [`DiffOpRemove`]s and keys missing from
+/// `from` become [`DiffOpAdd`]s (with `view` left `None` for the caller to
+/// fill in); an empty `to` instead yields a diff with only `clear` set.
+/// Every surviving key becomes a [`DiffOpMove`] carrying its old
+/// and new index, even when that index didn't change, so `apply_diff` can
+/// relocate every survivor from a single pre-take snapshot without losing
+/// one to an overwrite. The longest increasing subsequence of
+/// `new_index_to_old_index` is still computed to identify which survivors
+/// are already in place; `apply_diff` uses that to report whether a
+/// DOM/taffy reorder is needed — it must never be used to skip the array
+/// relocation itself.
+pub(crate) fn diff<K: Eq + Hash, V>(from: &FxIndexSet<K>, to: &FxIndexSet<K>) -> Diff<V> {
+    if from.is_empty() && to.is_empty() {
+        return Diff::default();
+    } else if to.is_empty() {
+        return Diff {
+            clear: true,
+            ..Default::default()
+        };
+    } else if from.is_empty() {
+        return Diff {
+            added: to
+                .iter()
+                .enumerate()
+                .map(|(at, _)| DiffOpAdd { at, view: None })
+                .collect(),
+            ..Default::default()
+        };
+    }
+
+    let removed = from
+        .iter()
+        .enumerate()
+        .filter(|(_, key)| !to.contains(*key))
+        .map(|(at, _)| DiffOpRemove { at })
+        .collect();
+
+    let new_index_to_old_index = to
+        .iter()
+        .map(|key| from.get_index_of(key))
+        .collect::<Vec<_>>();
+
+    let lis = longest_increasing_subsequence(&new_index_to_old_index);
+
+    let mut added = SmallVec::new();
+    let mut moved = SmallVec::new();
+    let mut lis = lis.into_iter().peekable();
+
+    for (new_index, old_index) in new_index_to_old_index.into_iter().enumerate() {
+        match old_index {
+            None => added.push(DiffOpAdd {
+                at: new_index,
+                view: None,
+            }),
+            Some(old_index) => {
+                let in_place = lis.peek() == Some(&new_index);
+                if in_place {
+                    lis.next();
+                }
+                moved.push(DiffOpMove {
+                    from: old_index,
+                    to: new_index,
+                    in_place,
+                });
+            }
+        }
+    }
+
+    Diff {
+        removed,
+        moved,
+        added,
+        clear: false,
+    }
+}
+
+/// Longest strictly increasing subsequence of the `Some` entries of `seq`,
+/// returned as the set of `seq` indices (not values) that belong to it.
+/// `None` entries (newly added keys with no prior index) are skipped.
+fn longest_increasing_subsequence(seq: &[Option<usize>]) -> Vec<usize> {
+    let mut predecessors = vec![0usize; seq.len()];
+    let mut tails: Vec<usize> = Vec::new();
+
+    for (i, value) in seq.iter().enumerate() {
+        let Some(value) = value else { continue };
+        let pos = tails.partition_point(|&j| seq[j].unwrap() < *value);
+        if pos > 0 {
+            predecessors[i] = tails[pos - 1];
+        }
+        if pos == tails.len() {
+            tails.push(i);
+        } else {
+            tails[pos] = i;
+        }
+    }
+
+    let mut result = vec![0usize; tails.len()];
+    if let Some(mut k) = tails.last().copied() {
+        for slot in result.iter_mut().rev() {
+            *slot = k;
+            k = predecessors[k];
+        }
+    }
+    result
+}
+
+/// Applies a [`Diff`] to `children`, relocating existing `(V, ScopeDisposer)`
+/// slots on a move instead of disposing and rebuilding them, so per-child
+/// reactive state survives a reorder. Returns whether the resulting order
+/// actually needs a DOM/taffy reorder — `false` only when every surviving
+/// key was already on the longest increasing subsequence, i.e. nothing was
+/// added, removed, or moved out of place.
+pub(crate) fn apply_diff<T, V>(
+    cx: AppContext,
+    app_state: &mut AppState,
+    diff: Diff<T>,
+    children: &mut Vec<Option<(V, ScopeDisposer)>>,
+    view_fn: &impl Fn(AppContext, T) -> V,
+) -> bool
+where
+    V: View,
+{
+    if diff.clear {
+        for child in children.drain(..).flatten() {
+            app_state.remove_view(child.0.id());
+        }
+        return true;
+    }
+
+    for op in &diff.removed {
+        if let Some((view, _)) = children[op.at].take() {
+            app_state.remove_view(view.id());
+        }
+    }
+
+    // `diff.moved` covers every surviving key (old-index space on `from`,
+    // new-index space on `to`), so the new vector's length is exactly
+    // `moved.len() + added.len()` — not `children.len() + added.len()`,
+    // which would still include the removed slots. Build it fresh rather
+    // than resizing in place, since `from` and `to` index two different
+    // spaces and can't be reconciled by shrinking/growing one vector.
+    let new_len = diff.moved.len() + diff.added.len();
+    let mut new_children = Vec::with_capacity(new_len);
+    new_children.resize_with(new_len, || None);
+
+    let needs_reorder = !diff.removed.is_empty()
+        || !diff.added.is_empty()
+        || diff.moved.iter().any(|move_op| !move_op.in_place);
+
+    for move_op in diff.moved {
+        new_children[move_op.to] = children[move_op.from].take();
+    }
+
+    for op in diff.added {
+        let item = op.view.unwrap();
+        let (view, disposer) = cx.scope.run_child_scope(|scope| {
+            let mut cx = cx;
+            cx.scope = scope;
+            view_fn(cx, item)
+        });
+        new_children[op.at] = Some((view, disposer));
+    }
+
+    *children = new_children;
+    needs_reorder
+}
@@ -1,21 +1,82 @@
-use std::{hash::Hash, marker::PhantomData};
+use std::{future::Future, hash::Hash, marker::PhantomData};
 
-use leptos_reactive::{create_effect, ScopeDisposer};
+use leptos_reactive::{create_effect, create_resource, Scope, ScopeDisposer};
 use smallvec::SmallVec;
 use taffy::style::Display;
 
 use crate::{
     app::AppContext,
-    context::{EventCx, UpdateCx},
+    context::{AppState, EventCx, UpdateCx},
+    event::Event,
     id::Id,
+    keyboard::{Key, KeyEvent, Modifiers, NamedKey},
     view::{ChangeFlags, View},
 };
 
 use super::{apply_diff, diff, Diff, DiffOpAdd, FxIndexSet, HashRun};
 
-enum TabState<V> {
-    Diff(Box<Diff<V>>),
+enum TabState<T, V> {
+    Diff(Box<Diff<T>>),
     Active(usize),
+    /// The async body for the item tagged with the token at `.0` finished
+    /// resolving; `.1` is its real view, to be swapped in for the fallback
+    /// currently occupying that item's slot. The token is resolved against
+    /// its current position in `self.tokens` rather than an index captured at add
+    /// time, since a later diff may have moved or removed the slot by then.
+    Resolved(Id, V),
+}
+
+/// Controls when a tab's child is constructed and whether it is torn down
+/// again once it stops being the active tab.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TabMode {
+    /// Build every child up front, same as before this mode existed.
+    #[default]
+    Eager,
+    /// Defer building a child until the first time it becomes active, then
+    /// keep it resident.
+    Lazy,
+    /// Like `Lazy`, but also dispose a child (and its `ScopeDisposer`) as
+    /// soon as it stops being active, rebuilding it next time it is shown.
+    LazyDrop,
+}
+
+/// Which direction [`Tab::navigate`] should move `active` in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TabNavDirection {
+    Next,
+    Prev,
+}
+
+/// Returns the tab-navigation direction requested by the Ctrl+Tab /
+/// Ctrl+Shift+Tab chord, if any. This chord is global: it's handled even
+/// when some descendant other than the tab strip has focus.
+fn tab_nav_chord_direction(key_event: &KeyEvent) -> Option<TabNavDirection> {
+    if key_event.key != Key::Named(NamedKey::Tab)
+        || !key_event.modifiers.contains(Modifiers::CONTROL)
+    {
+        return None;
+    }
+    if key_event.modifiers.contains(Modifiers::SHIFT) {
+        Some(TabNavDirection::Prev)
+    } else {
+        Some(TabNavDirection::Next)
+    }
+}
+
+/// Returns the tab-navigation direction requested by the (unmodified)
+/// arrow keys, if any. Unlike the Ctrl+Tab chord, this is only honored
+/// while the tab strip itself has focus, so it doesn't steal word-wise
+/// cursor movement (e.g. Ctrl+Left/Right) from a focused child.
+fn tab_nav_arrow_direction(key_event: &KeyEvent) -> Option<TabNavDirection> {
+    if !key_event.modifiers.is_empty() {
+        return None;
+    }
+    match key_event.key {
+        Key::Named(NamedKey::ArrowRight) => Some(TabNavDirection::Next),
+        Key::Named(NamedKey::ArrowLeft) => Some(TabNavDirection::Prev),
+        _ => None,
+    }
 }
 
 pub struct Tab<V, VF, T>
@@ -26,8 +87,28 @@ where
 {
     id: Id,
     active: usize,
+    mode: TabMode,
     children: Vec<Option<(V, ScopeDisposer)>>,
+    items: Vec<Option<T>>,
     view_fn: VF,
+    /// Set only by [`tab_async`]: builds the placeholder view for a newly
+    /// added slot while its real content is still resolving. Kept separate
+    /// from `view_fn` (whose signature takes the item) since the fallback
+    /// doesn't depend on it.
+    fallback: Option<Box<dyn Fn(AppContext) -> V>>,
+    /// Set only by [`tab_async`]: spawns the item's resource and the effect
+    /// watching it, inside the per-slot child scope `apply_diff_fallback`
+    /// builds for the fallback view, and tags the resolved view with the
+    /// given token so a later [`TabState::Resolved`] can find its slot. Spawning
+    /// here (rather than eagerly on the tab's own scope) means removing or
+    /// replacing the slot disposes the resource and its effect along with
+    /// the fallback instead of leaking them for the tab's lifetime.
+    spawn_resource: Option<Box<dyn Fn(AppContext, Scope, T, Id)>>,
+    /// Parallel to `children`/`items`, set only by [`tab_async`]: the token
+    /// each slot was last tagged with, so [`TabState::Resolved`] can locate
+    /// a slot by its token after later diffs have moved or removed it.
+    tokens: Vec<Option<Id>>,
+    on_active: Option<Box<dyn Fn(usize)>>,
     phatom: PhantomData<T>,
     cx: AppContext,
 }
@@ -46,7 +127,7 @@ where
     K: Eq + Hash + 'static,
     VF: Fn(AppContext, T) -> V + 'static,
     V: View + 'static,
-    T: 'static,
+    T: Clone + 'static,
 {
     let id = cx.new_id();
 
@@ -77,28 +158,331 @@ where
             }
             diff
         };
-        AppContext::update_state(id, TabState::Diff(Box::new(diff)), false);
+        AppContext::update_state(id, TabState::<T, V>::Diff(Box::new(diff)), false);
         HashRun(hashed_items)
     });
 
     create_effect(cx.scope, move |_| {
         let active = active_fn();
-        AppContext::update_state(id, TabState::Active::<T>(active), false);
+        AppContext::update_state(id, TabState::<T, V>::Active(active), false);
     });
 
     Tab {
         id,
         active: 0,
+        mode: TabMode::Eager,
         children: Vec::new(),
+        items: Vec::new(),
         view_fn,
+        fallback: None,
+        spawn_resource: None,
+        tokens: Vec::new(),
+        on_active: None,
+        phatom: PhantomData::default(),
+        cx: child_cx,
+    }
+}
+
+/// Like [`tab`], but each child's content is produced asynchronously: a
+/// `view_fn(cx, item)` future is spawned as a [`leptos_reactive::create_resource`]
+/// per added tab, and until it resolves the slot shows `fallback_fn`'s view.
+/// `update` swaps the fallback out for the real view once it's ready; the
+/// active/inactive display logic and active-child dispatch don't need to
+/// know which one currently occupies the slot.
+///
+/// The resource and its watching effect aren't spawned until the item is
+/// actually applied to a slot (see `apply_diff_fallback`), and then inside
+/// that slot's own child scope rather than the tab's — so removing or
+/// replacing the item disposes them along with the fallback instead of
+/// leaking them for the tab's lifetime.
+pub fn tab_async<IF, I, T, KF, K, VF, FU, FF, V>(
+    cx: AppContext,
+    active_fn: impl Fn() -> usize + 'static,
+    each_fn: IF,
+    key_fn: KF,
+    view_fn: VF,
+    fallback_fn: FF,
+) -> Tab<V, impl Fn(AppContext, T) -> V + 'static, T>
+where
+    IF: Fn() -> I + 'static,
+    I: IntoIterator<Item = T>,
+    KF: Fn(&T) -> K + 'static,
+    K: Eq + Hash + 'static,
+    VF: Fn(AppContext, T) -> FU + Clone + 'static,
+    FU: Future<Output = V> + 'static,
+    FF: Fn(AppContext) -> V + Clone + 'static,
+    V: View + Clone + 'static,
+    T: Clone + 'static,
+{
+    let id = cx.new_id();
+
+    let mut child_cx = cx;
+    child_cx.id = id;
+
+    create_effect(cx.scope, move |prev_hash_run| {
+        let items = each_fn();
+        let items = items.into_iter().collect::<SmallVec<[_; 128]>>();
+        let hashed_items = items.iter().map(&key_fn).collect::<FxIndexSet<_>>();
+        let diff = if let Some(HashRun(prev_hash_run)) = prev_hash_run {
+            let mut cmds = diff(&prev_hash_run, &hashed_items);
+            let mut items = items
+                .into_iter()
+                .map(|i| Some(i))
+                .collect::<SmallVec<[Option<_>; 128]>>();
+            for added in &mut cmds.added {
+                added.view = Some(items[added.at].take().unwrap());
+            }
+            cmds
+        } else {
+            let mut diff = Diff::default();
+            for (i, item) in each_fn().into_iter().enumerate() {
+                diff.added.push(DiffOpAdd {
+                    at: i,
+                    view: Some(item),
+                });
+            }
+            diff
+        };
+        AppContext::update_state(id, TabState::<T, V>::Diff(Box::new(diff)), false);
+        HashRun(hashed_items)
+    });
+
+    create_effect(cx.scope, move |_| {
+        let active = active_fn();
+        AppContext::update_state(id, TabState::<T, V>::Active(active), false);
+    });
+
+    let view_fn_fallback = fallback_fn.clone();
+    let spawn_resource: Box<dyn Fn(AppContext, Scope, T, Id)> =
+        Box::new(move |item_cx: AppContext, scope: Scope, item: T, token: Id| {
+            let view_fn = view_fn.clone();
+            let resource = create_resource(scope, || (), move |_| view_fn(item_cx, item.clone()));
+            create_effect(scope, move |_| {
+                if let Some(view) = resource.read(scope) {
+                    AppContext::update_state(id, TabState::<T, V>::Resolved(token, view), false);
+                }
+            });
+        });
+
+    Tab {
+        id,
+        active: 0,
+        mode: TabMode::Eager,
+        children: Vec::new(),
+        items: Vec::new(),
+        view_fn: move |cx: AppContext, _item: T| view_fn_fallback(cx),
+        fallback: Some(Box::new(fallback_fn)),
+        spawn_resource: Some(spawn_resource),
+        tokens: Vec::new(),
+        on_active: None,
         phatom: PhantomData::default(),
         cx: child_cx,
     }
 }
 
+impl<V, VF, T> Tab<V, VF, T>
+where
+    V: View,
+    VF: Fn(AppContext, T) -> V + 'static,
+    T: Clone + 'static,
+{
+    /// Sets the construction mode used for this tab's children. Defaults to
+    /// [`TabMode::Eager`].
+    pub fn mode(mut self, mode: TabMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Registers a callback fired with the new index whenever keyboard
+    /// navigation (see [`Tab::event`]) changes the active tab.
+    pub fn on_active(mut self, on_active: impl Fn(usize) + 'static) -> Self {
+        self.on_active = Some(Box::new(on_active));
+        self
+    }
+
+    /// Returns whether the slot at `index` is occupied, either by a built
+    /// child (`Eager`) or a pending item waiting to be built (`Lazy`/`LazyDrop`).
+    fn slot_occupied(&self, index: usize) -> bool {
+        match self.mode {
+            TabMode::Eager => matches!(self.children.get(index), Some(Some(_))),
+            TabMode::Lazy | TabMode::LazyDrop => matches!(self.items.get(index), Some(Some(_))),
+        }
+    }
+
+    /// Advances `active` by one tab in `direction`, wrapping around and
+    /// skipping any `None` slots, then notifies `on_active` and pushes the
+    /// change back through `update_state` as a [`TabState::Active`] for this
+    /// tab. Whatever `active_fn` reads is not written here.
+    fn navigate(&mut self, direction: TabNavDirection) {
+        let len = self.children.len();
+        if len == 0 {
+            return;
+        }
+
+        let mut next = self.active;
+        for _ in 0..len {
+            next = match direction {
+                TabNavDirection::Next => (next + 1) % len,
+                TabNavDirection::Prev => (next + len - 1) % len,
+            };
+            if self.slot_occupied(next) {
+                break;
+            }
+        }
+        if next == self.active {
+            return;
+        }
+
+        if let Some(on_active) = &self.on_active {
+            on_active(next);
+        }
+        AppContext::update_state(self.id, TabState::<T, V>::Active(next), false);
+    }
+
+    /// Builds the child at `index` from `self.items` if it isn't resident
+    /// yet. No-op if the slot is already built or there is no pending item.
+    fn ensure_built(&mut self, index: usize) {
+        if !matches!(self.children.get(index), Some(None)) {
+            return;
+        }
+        let Some(item) = self.items.get(index).and_then(|item| item.clone()) else {
+            return;
+        };
+        let view_fn = &self.view_fn;
+        let cx = self.cx;
+        let (view, disposer) = cx.scope.run_child_scope(|scope| {
+            let mut cx = cx;
+            cx.scope = scope;
+            view_fn(cx, item)
+        });
+        self.children[index] = Some((view, disposer));
+    }
+
+    /// Applies a diff of raw items (rather than built views) to
+    /// `self.items`/`self.children` for the `Lazy`/`LazyDrop` modes, leaving
+    /// newly added slots unbuilt until they are activated. Returns whether
+    /// the resulting order needs a DOM/taffy reorder, same convention as
+    /// [`apply_diff`].
+    ///
+    /// Mirrors [`apply_diff`]'s relocate-from-a-fresh-vector approach:
+    /// `diff.moved` already covers every surviving key, old-index space on
+    /// `from` and new-index space on `to`, so the rebuilt vectors are sized
+    /// `moved.len() + added.len()`, not the old length plus `added.len()`,
+    /// which would leave stray trailing `None`s once there are removals.
+    fn apply_diff_lazy(&mut self, diff: Diff<T>) -> bool {
+        if diff.clear {
+            self.items.clear();
+            self.children.clear();
+            return true;
+        }
+
+        let new_len = diff.moved.len() + diff.added.len();
+        let mut new_items = Vec::with_capacity(new_len);
+        new_items.resize_with(new_len, || None);
+        let mut new_children = Vec::with_capacity(new_len);
+        new_children.resize_with(new_len, || None);
+
+        let needs_reorder = !diff.removed.is_empty()
+            || !diff.added.is_empty()
+            || diff.moved.iter().any(|move_op| !move_op.in_place);
+
+        for move_op in &diff.moved {
+            new_items[move_op.to] = self.items[move_op.from].take();
+            new_children[move_op.to] = self.children[move_op.from].take();
+        }
+
+        for op in diff.added {
+            new_items[op.at] = op.view;
+        }
+
+        self.items = new_items;
+        self.children = new_children;
+        needs_reorder
+    }
+
+    /// Applies a diff the same way [`apply_diff`] would, except a newly
+    /// added slot is built from `self.fallback` instead of `view_fn` — a
+    /// `tab_async` child's real content isn't available synchronously, so
+    /// `view_fn` (here an item-ignoring adapter over the fallback) is never
+    /// routed through this path. The item's resource and its watching effect
+    /// (`self.spawn_resource`) are spawned inside that same per-slot child
+    /// scope, tagged with a freshly minted token stored in `self.tokens` —
+    /// so when the slot is later removed or replaced, disposing its scope
+    /// tears down the resource and effect along with the fallback view,
+    /// instead of leaking them for the tab's lifetime. `self.tokens` is kept
+    /// in lockstep with `self.children` purely so [`TabState::Resolved`] can
+    /// find a slot's current position by its token after later moves. Returns
+    /// whether the resulting order needs a DOM/taffy reorder, same
+    /// convention as [`apply_diff`].
+    fn apply_diff_fallback(&mut self, app_state: &mut AppState, diff: Diff<T>) -> bool {
+        let fallback = self
+            .fallback
+            .as_ref()
+            .expect("apply_diff_fallback requires a fallback (tab_async only)");
+        let spawn_resource = self
+            .spawn_resource
+            .as_ref()
+            .expect("apply_diff_fallback requires spawn_resource (tab_async only)");
+
+        if diff.clear {
+            for (view, _) in self.children.drain(..).flatten() {
+                app_state.remove_view(view.id());
+            }
+            self.items.clear();
+            self.tokens.clear();
+            return true;
+        }
+
+        for op in &diff.removed {
+            if let Some((view, _)) = self.children[op.at].take() {
+                app_state.remove_view(view.id());
+            }
+        }
+
+        let new_len = diff.moved.len() + diff.added.len();
+        let mut new_items = Vec::with_capacity(new_len);
+        new_items.resize_with(new_len, || None);
+        let mut new_children = Vec::with_capacity(new_len);
+        new_children.resize_with(new_len, || None);
+        let mut new_tokens = Vec::with_capacity(new_len);
+        new_tokens.resize_with(new_len, || None);
+
+        let needs_reorder = !diff.removed.is_empty()
+            || !diff.added.is_empty()
+            || diff.moved.iter().any(|move_op| !move_op.in_place);
+
+        for move_op in &diff.moved {
+            new_items[move_op.to] = self.items[move_op.from].take();
+            new_children[move_op.to] = self.children[move_op.from].take();
+            new_tokens[move_op.to] = self.tokens[move_op.from].take();
+        }
+
+        let cx = self.cx;
+        for op in diff.added {
+            let item = op.view.unwrap();
+            let token = cx.new_id();
+            let (view, disposer) = cx.scope.run_child_scope(|scope| {
+                let mut child_cx = cx;
+                child_cx.scope = scope;
+                spawn_resource(child_cx, scope, item.clone(), token);
+                fallback(child_cx)
+            });
+            new_items[op.at] = Some(item);
+            new_children[op.at] = Some((view, disposer));
+            new_tokens[op.at] = Some(token);
+        }
+
+        self.items = new_items;
+        self.children = new_children;
+        self.tokens = new_tokens;
+        needs_reorder
+    }
+}
+
 impl<V: View + 'static, VF, T> View for Tab<V, VF, T>
 where
     VF: Fn(AppContext, T) -> V + 'static,
+    T: Clone + 'static,
 {
     fn id(&self) -> Id {
         self.id
@@ -121,23 +505,59 @@ where
         cx: &mut UpdateCx,
         state: Box<dyn std::any::Any>,
     ) -> crate::view::ChangeFlags {
-        if let Ok(state) = state.downcast::<TabState<T>>() {
-            match *state {
+        if let Ok(state) = state.downcast::<TabState<T, V>>() {
+            let needs_reorder = match *state {
                 TabState::Diff(diff) => {
-                    apply_diff(
-                        self.cx,
-                        cx.app_state,
-                        *diff,
-                        &mut self.children,
-                        &self.view_fn,
-                    );
+                    if self.fallback.is_some() {
+                        self.apply_diff_fallback(cx.app_state, *diff)
+                    } else {
+                        match self.mode {
+                            TabMode::Eager => apply_diff(
+                                self.cx,
+                                cx.app_state,
+                                *diff,
+                                &mut self.children,
+                                &self.view_fn,
+                            ),
+                            TabMode::Lazy | TabMode::LazyDrop => {
+                                let needs_reorder = self.apply_diff_lazy(*diff);
+                                self.ensure_built(self.active);
+                                needs_reorder
+                            }
+                        }
+                    }
                 }
                 TabState::Active(active) => {
+                    if self.mode == TabMode::LazyDrop && active != self.active {
+                        if let Some(slot) = self.children.get_mut(self.active) {
+                            if let Some((view, _)) = slot.take() {
+                                cx.app_state.remove_view(view.id());
+                            }
+                        }
+                    }
                     self.active = active;
+                    if self.mode != TabMode::Eager {
+                        self.ensure_built(active);
+                    }
+                    true
                 }
-            }
+                TabState::Resolved(token, view) => {
+                    let slot = self
+                        .tokens
+                        .iter()
+                        .position(|slot_token| *slot_token == Some(token));
+                    if let Some(index) = slot {
+                        let cx = self.cx;
+                        let (view, disposer) = cx.scope.run_child_scope(|_| view);
+                        self.children[index] = Some((view, disposer));
+                    }
+                    true
+                }
+            };
             cx.request_layout(self.id());
-            cx.reset_children_layout(self.id);
+            if needs_reorder {
+                cx.reset_children_layout(self.id);
+            }
             ChangeFlags::LAYOUT
         } else {
             ChangeFlags::empty()
@@ -175,12 +595,22 @@ where
         }
     }
 
-    fn event(
-        &mut self,
-        cx: &mut EventCx,
-        id_path: Option<&[Id]>,
-        event: crate::event::Event,
-    ) -> bool {
+    fn event(&mut self, cx: &mut EventCx, id_path: Option<&[Id]>, event: Event) -> bool {
+        if let Event::KeyDown(key_event) = &event {
+            if let Some(direction) = tab_nav_chord_direction(key_event) {
+                self.navigate(direction);
+                return true;
+            }
+
+            let is_strip_focused = id_path.is_none() || cx.app_state.is_focused(self.id);
+            if is_strip_focused {
+                if let Some(direction) = tab_nav_arrow_direction(key_event) {
+                    self.navigate(direction);
+                    return true;
+                }
+            }
+        }
+
         if let Some(Some((child, _))) = self.children.get_mut(self.active) {
             child.event_main(cx, id_path, event)
         } else {